@@ -10,6 +10,125 @@
 //! for those use cases which need longer lived return types.
 //!
 
+/// A pattern that can be matched against a `char` boundary within a `&str`.
+///
+/// This mirrors (a small slice of) the standard library's own pattern abstraction, and lets
+/// every split function in this module accept a single `char`, a multi-character `&str`, a set
+/// of `char`s, or an arbitrary `Fn(char) -> bool` predicate, instead of being hard-coded to one
+/// of those.
+pub trait Pattern {
+    /// If this pattern matches `haystack` starting at the byte offset `byte_idx`, returns the
+    /// byte length of that match. Returns `None` if there is no match at that position.
+    ///
+    /// `byte_idx` is always assumed to land on a `char` boundary of `haystack`.
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize>;
+}
+
+impl Pattern for char {
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize> {
+        let c = haystack[byte_idx..].chars().next()?;
+        (c == *self).then_some(c.len_utf8())
+    }
+}
+
+impl Pattern for &str {
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        haystack[byte_idx..].starts_with(*self).then_some(self.len())
+    }
+}
+
+impl Pattern for &[char] {
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize> {
+        let c = haystack[byte_idx..].chars().next()?;
+        self.contains(&c).then_some(c.len_utf8())
+    }
+}
+
+impl<const N: usize> Pattern for [char; N] {
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize> {
+        self.as_slice().matches_at(haystack, byte_idx)
+    }
+}
+
+impl<const N: usize> Pattern for &[char; N] {
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize> {
+        self.as_slice().matches_at(haystack, byte_idx)
+    }
+}
+
+impl<F> Pattern for F
+where
+    F: Fn(char) -> bool,
+{
+    fn matches_at(&self, haystack: &str, byte_idx: usize) -> Option<usize> {
+        let c = haystack[byte_idx..].chars().next()?;
+        self(c).then_some(c.len_utf8())
+    }
+}
+
+/// Returns the byte length of the `char` starting at `byte_idx` in `s`.
+///
+/// Used by the scanning loops below to advance one `char` at a time over non-matching input.
+fn char_len_at(s: &str, byte_idx: usize) -> usize {
+    s[byte_idx..]
+        .chars()
+        .next()
+        .map(char::len_utf8)
+        .unwrap_or(1)
+}
+
+/// A lazy, borrowing iterator over the segments of a string split on a [`Pattern`], empty
+/// segments included.
+///
+/// Built by [`split_iter`]. Scans at most as far as the caller actually consumes, so unlike
+/// [`split_on_delimiters`] it never allocates a `Vec` or visits bytes past the last item taken.
+pub struct Split<'a, P> {
+    remainder: Option<&'a str>,
+    pattern: P,
+}
+
+impl<'a, P: Pattern> Iterator for Split<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.remainder?;
+        let mut idx = 0;
+        while idx < s.len() {
+            if let Some(len) = self.pattern.matches_at(s, idx) {
+                self.remainder = Some(&s[idx + len..]);
+                return Some(&s[..idx]);
+            }
+            idx += char_len_at(s, idx);
+        }
+        self.remainder = None;
+        Some(s)
+    }
+}
+
+/// Returns an iterator over the segments of `s` split on `pattern`, left to right.
+///
+/// Unlike [`split_on_delimiters`], empty segments (including a trailing one when `s` ends with
+/// `pattern`) are yielded rather than stripped - this mirrors `str::split`.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::split_iter;
+/// let mut it = split_iter("a::b::c", "::");
+/// assert_eq!(it.next(), Some("a"));
+/// assert_eq!(it.next(), Some("b"));
+/// assert_eq!(it.next(), Some("c"));
+/// assert_eq!(it.next(), None);
+/// ```
+pub fn split_iter<P: Pattern>(s: &str, pattern: P) -> Split<'_, P> {
+    Split {
+        remainder: Some(s),
+        pattern,
+    }
+}
+
 /// Split on multiple delimiters - this will handle multiple delimiters,
 /// splitting the input string on any of the delimiters or a combination of them.
 /// This function uses lifetime parameters meaning that the returned vector will have a lifetime
@@ -17,7 +136,8 @@
 ///
 /// # Parameters:
 /// * `input_string`: A string slice to be split.
-/// * `delimiters`: an array slice of chars
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
 ///
 /// # Returns:
 /// * A `Vec<&str>` containing substrings of the input string `s`. The Vector is annotated with a lifetime parameter
@@ -39,22 +159,17 @@
 /// let expected_output: Vec<&str> = ["html", "body", "h1", "Heading", "h1", "body", "html"].to_vec();
 /// assert_eq!(expected_output, split_on_delimiters(&input, &delimiters));
 ///```
-pub fn split_on_delimiters<'a>(input_string: &'a str, delimiters: &[char]) -> Vec<&'a str> {
-    let mut output = Vec::new();
-    let mut start = 0;
-    for (i, c) in input_string.char_indices() {
-        for d in delimiters {
-            if c == *d {
-                output.push(&input_string[start..i]);
-                start = i + c.len_utf8();
-            }
-        }
-    }
-    if start < input_string.len() {
-        output.push(&input_string[start..]);
-    }
-    output.retain(|item| !item.is_empty());
-    output
+///
+/// ```
+/// use rust_strings::splits::split_on_delimiters;
+/// let input = "a::b::c";
+/// let expected_output: Vec<&str> = ["a", "b", "c"].to_vec();
+/// assert_eq!(expected_output, split_on_delimiters(input, "::"));
+/// ```
+pub fn split_on_delimiters<'a, P: Pattern>(input_string: &'a str, pattern: P) -> Vec<&'a str> {
+    split_iter(input_string, pattern)
+        .filter(|item| !item.is_empty())
+        .collect()
 }
 
 /// Split on multiple delimiters - this will handle multiple delimiters,
@@ -63,7 +178,8 @@ pub fn split_on_delimiters<'a>(input_string: &'a str, delimiters: &[char]) -> Ve
 ///
 /// # Parameters:
 /// * `input_string`: A string slice to be split.
-/// * `delimiters`: an array slice of chars
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
 ///
 /// # Returns:
 /// * A `Vec<String>` containing substrings of the input string `s`.
@@ -76,22 +192,11 @@ pub fn split_on_delimiters<'a>(input_string: &'a str, delimiters: &[char]) -> Ve
 /// let expected_output: Vec<String> = ["big".to_string(), "brown".to_string(), "cow".to_string()].to_vec();
 /// assert_eq!(expected_output, split_on_delimiters_returns_owned(&input, &delimiters));
 /// ```
-pub fn split_on_delimiters_returns_owned(s: &str, delimiters: &[char]) -> Vec<String> {
-    let mut output: Vec<String> = Vec::new();
-    let mut start = 0;
-    for (i, c) in s.char_indices() {
-        for d in delimiters {
-            if c == *d {
-                output.push(s[start..i].to_owned().parse().unwrap());
-                start = i + c.len_utf8();
-            }
-        }
-    }
-    if start < s.len() {
-        output.push(s[start..].parse().unwrap());
-    }
-    output.retain(|item| !item.is_empty());
-    output
+pub fn split_on_delimiters_returns_owned<P: Pattern>(s: &str, pattern: P) -> Vec<String> {
+    split_on_delimiters(s, pattern)
+        .into_iter()
+        .map(String::from)
+        .collect()
 }
 
 /// Splits a string into substrings while keeping the delimiter at the end of each substring.
@@ -100,7 +205,7 @@ pub fn split_on_delimiters_returns_owned(s: &str, delimiters: &[char]) -> Vec<St
 ///
 /// # Parameters
 /// * `s`: A string slice to be split.
-/// * `delimiter`: The character used as the delimiter to split the string.
+/// * `pattern`: anything implementing [`Pattern`] used as the delimiter to split the string.
 ///
 /// # Returns
 /// * A `Vec<&str>` containing substrings of the input string `s`. Each substring includes the
@@ -129,20 +234,65 @@ pub fn split_on_delimiters_returns_owned(s: &str, delimiters: &[char]) -> Vec<St
 /// assert_eq!(result, Vec::<&str>::new());
 /// ```
 ///
+/// ```
+/// use rust_strings::splits::split_keeping_delimiter;
+/// let s = "one two  three";
+/// let result = split_keeping_delimiter(s, |c: char| c.is_whitespace());
+/// assert_eq!(result, vec!["one ", "two ", " "]);
+/// ```
+///
 /// ## Note
 /// - The function does not include substrings after the last delimiter.
 /// - If the input string does not include the delimiter, an empty vector will be returned.
 ///
-pub fn split_keeping_delimiter<'a>(s: &'a str, delimiter: char) -> Vec<&'a str> {
-    let mut output = Vec::new();
-    let mut start = 0;
-    for (i, c) in s.char_indices() {
-        if c == delimiter {
-            output.push(&s[start..=i]);
-            start = i + 1;
+pub fn split_keeping_delimiter<'a, P: Pattern>(s: &'a str, pattern: P) -> Vec<&'a str> {
+    split_keeping_delimiter_iter(s, pattern).collect()
+}
+
+/// A lazy, borrowing iterator over the segments of a string split on a [`Pattern`], with the
+/// matched delimiter kept at the end of each segment.
+///
+/// Built by [`split_keeping_delimiter_iter`]. Unlike [`Split`], it does not include a trailing
+/// segment after the last delimiter - it simply stops once no more matches are found.
+pub struct SplitKeepingDelimiter<'a, P> {
+    remainder: &'a str,
+    pattern: P,
+}
+
+impl<'a, P: Pattern> Iterator for SplitKeepingDelimiter<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.remainder;
+        let mut idx = 0;
+        while idx < s.len() {
+            if let Some(len) = self.pattern.matches_at(s, idx) {
+                self.remainder = &s[idx + len..];
+                return Some(&s[..idx + len]);
+            }
+            idx += char_len_at(s, idx);
         }
+        self.remainder = "";
+        None
+    }
+}
+
+/// Returns an iterator over the segments of `s` split on `pattern`, with the matched delimiter
+/// kept at the end of each segment.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::split_keeping_delimiter_iter;
+/// let mut it = split_keeping_delimiter_iter("hello,world,here", ',');
+/// assert_eq!(it.next(), Some("hello,"));
+/// assert_eq!(it.next(), Some("world,"));
+/// assert_eq!(it.next(), None);
+/// ```
+pub fn split_keeping_delimiter_iter<P: Pattern>(s: &str, pattern: P) -> SplitKeepingDelimiter<'_, P> {
+    SplitKeepingDelimiter {
+        remainder: s,
+        pattern,
     }
-    output
 }
 
 /// Splits a given input string by the specified delimiter while retaining the delimiter
@@ -150,7 +300,7 @@ pub fn split_keeping_delimiter<'a>(s: &'a str, delimiter: char) -> Vec<&'a str>
 ///
 /// # Arguments
 /// * `input_string` - A reference to the string that will be split.
-/// * `delimiter` - A character used as the delimiter to split the string.
+/// * `pattern` - anything implementing [`Pattern`] used as the delimiter to split the string.
 ///
 /// # Returns
 /// * `Vec<String>` - A vector of owned strings resulting from the split,
@@ -164,16 +314,69 @@ pub fn split_keeping_delimiter<'a>(s: &'a str, delimiter: char) -> Vec<&'a str>
 /// let result = split_keeping_delimiter_returns_owned(input, delimiter);
 /// assert_eq!(result, vec!["hello,", "world,"]);
 /// ```
-pub fn split_keeping_delimiter_returns_owned(input_string: &str, delimiter: char) -> Vec<String> {
-    let mut output: Vec<String> = Vec::new();
-    let mut start = 0;
-    for (i, c) in input_string.char_indices() {
-        if c == delimiter {
-            output.push(input_string[start..=i].to_owned());
-            start = i + 1;
+pub fn split_keeping_delimiter_returns_owned<P: Pattern>(
+    input_string: &str,
+    pattern: P,
+) -> Vec<String> {
+    split_keeping_delimiter(input_string, pattern)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// A lazy, borrowing iterator that yields at most `n` segments of a string split on a [`Pattern`],
+/// using RFC-979 item-count semantics: once `n - 1` segments have been yielded, the rest of the
+/// string is returned as the final item without any further scanning.
+///
+/// Built by [`splitn_iter`].
+pub struct SplitN<'a, P> {
+    remainder: Option<&'a str>,
+    pattern: P,
+    n: usize,
+}
+
+impl<'a, P: Pattern> Iterator for SplitN<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.remainder?;
+        if self.n == 1 {
+            self.remainder = None;
+            return Some(s);
+        }
+        let mut idx = 0;
+        while idx < s.len() {
+            if let Some(len) = self.pattern.matches_at(s, idx) {
+                self.remainder = Some(&s[idx + len..]);
+                self.n -= 1;
+                return Some(&s[..idx]);
+            }
+            idx += char_len_at(s, idx);
         }
+        self.remainder = None;
+        Some(s)
+    }
+}
+
+/// Returns an iterator over at most `n` segments of `s` split on `pattern`.
+///
+/// `n` is the maximum number of items yielded; once `n - 1` items have been produced the entire
+/// remainder is returned as the final item without further scanning. `n == 0` yields no items.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::splitn_iter;
+/// let mut it = splitn_iter("a,b,c,d", ',', 2);
+/// assert_eq!(it.next(), Some("a"));
+/// assert_eq!(it.next(), Some("b,c,d"));
+/// assert_eq!(it.next(), None);
+/// ```
+pub fn splitn_iter<P: Pattern>(s: &str, pattern: P, n: usize) -> SplitN<'_, P> {
+    SplitN {
+        remainder: (n > 0).then_some(s),
+        pattern,
+        n,
     }
-    output
 }
 
 /// Splits a string slice into exactly N parts, padding with empty strings if needed. It uses
@@ -182,7 +385,7 @@ pub fn split_keeping_delimiter_returns_owned(input_string: &str, delimiter: char
 ///
 /// # Arguments:
 /// * `input_string`: a string slice to be split into parts.
-/// * `delimiter`: a char used to determine how/where to split the input_string
+/// * `pattern`: anything implementing [`Pattern`] used to determine how/where to split the input_string
 /// * `n`: usize to determine how many pieces to split input_string into.
 ///
 /// # Returns
@@ -193,39 +396,37 @@ pub fn split_keeping_delimiter_returns_owned(input_string: &str, delimiter: char
 /// ```
 /// use rust_strings::splits::split_into_n_parts;
 /// let input_string = "This is a string.";
-/// let delimiter = " ";
+/// let delimiter = ' ';
 /// let n = 3;
 /// let expected_outcome = vec!["This", "is", "a string."];
+/// assert_eq!(expected_outcome, split_into_n_parts(input_string, delimiter, n));
 /// ```
 ///
 /// ```
 /// use rust_strings::splits::split_into_n_parts;
 /// let input_string = "This is a string.";
-/// let delimiter = " ";
+/// let delimiter = ' ';
 /// let n = 4;
 /// let expected_outcome = vec!["This", "is", "a", "string."];
+/// assert_eq!(expected_outcome, split_into_n_parts(input_string, delimiter, n));
 /// ```
 ///
 /// ```
 /// use rust_strings::splits::split_into_n_parts;
 /// let input_string = "This is a string.";
-/// let delimiter = " ";
+/// let delimiter = ' ';
 /// let n = 5;
 /// let expected_outcome = vec!["This", "is", "a", "string.", ""];
+/// assert_eq!(expected_outcome, split_into_n_parts(input_string, delimiter, n));
 /// ```
-pub fn split_into_n_parts<'a>(input_string: &'a str, delimiter: char, n: usize) -> Vec<&'a str> {
-    let mut output: Vec<&str> = Vec::new();
-    let count_expect_segments = input_string.chars().filter(|&x| x == delimiter).count() + 1;
-    if count_expect_segments == n {
-        output = input_string.split(delimiter).collect();
-    } else if count_expect_segments < n {
-        output = input_string.split(delimiter).collect();
-        for mut i in count_expect_segments..n {
-            output.push("");
-            i += 1;
-        }
-    } else {
-        output = input_string.splitn(n, delimiter).collect();
+pub fn split_into_n_parts<'a, P: Pattern>(
+    input_string: &'a str,
+    pattern: P,
+    n: usize,
+) -> Vec<&'a str> {
+    let mut output: Vec<&str> = splitn_iter(input_string, pattern, n).collect();
+    while output.len() < n {
+        output.push("");
     }
     output
 }
@@ -237,7 +438,7 @@ pub fn split_into_n_parts<'a>(input_string: &'a str, delimiter: char, n: usize)
 ///
 /// # Arguments:
 /// * `input_string`: a string slice to be split into parts.
-/// * `delimiter`: a char used to determine how/where to split the input_string
+/// * `pattern`: anything implementing [`Pattern`] used to determine how/where to split the input_string
 /// * `n`: usize to determine how many pieces to split input_string into.
 ///
 /// # Returns
@@ -275,29 +476,499 @@ pub fn split_into_n_parts<'a>(input_string: &'a str, delimiter: char, n: usize)
 ///                             "".to_string()];
 /// assert_eq!(expected_outcome, split_into_n_parts_returns_owned(input_string, delimiter, n));
 /// ```
-pub fn split_into_n_parts_returns_owned(
+pub fn split_into_n_parts_returns_owned<P: Pattern>(
     input_string: &str,
-    delimiter: char,
+    pattern: P,
     n: usize,
 ) -> Vec<String> {
-    let mut output: Vec<String> = Vec::new();
-    let count_expect_segments = input_string.chars().filter(|&x| x == delimiter).count() + 1;
-    if count_expect_segments == n {
-        output = input_string.split(delimiter).map(String::from).collect();
-    } else if count_expect_segments < n {
-        output = input_string.split(delimiter).map(String::from).collect();
-        for mut i in count_expect_segments..n {
-            output.push(String::from(""));
-            i += 1;
+    split_into_n_parts(input_string, pattern, n)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Finds the rightmost match of `pattern` in `s`, returning its `(byte_idx, byte_len)`.
+///
+/// Used by the `rsplit*` family below to scan from the end of the string toward the start.
+fn rfind_match<P: Pattern>(s: &str, pattern: &P) -> Option<(usize, usize)> {
+    s.char_indices()
+        .rev()
+        .find_map(|(idx, _)| pattern.matches_at(s, idx).map(|len| (idx, len)))
+}
+
+/// Split on multiple delimiters, returning the segments from the end of the string toward the
+/// start.
+///
+/// This produces the same set of non-empty segments as [`split_on_delimiters`], but in reverse
+/// order (rightmost segment first), matching the ordering of std's `rsplit`. The segments are
+/// found by a left-to-right scan whose result is then reversed.
+///
+/// # Parameters:
+/// * `input_string`: A string slice to be split.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns:
+/// * A `Vec<&str>` containing substrings of the input string `s`, rightmost segment first.
+///
+/// # Example:
+/// ```
+/// use rust_strings::splits::rsplit_on_delimiters;
+/// let input = "a::b::c";
+/// let expected_output: Vec<&str> = ["c", "b", "a"].to_vec();
+/// assert_eq!(expected_output, rsplit_on_delimiters(input, "::"));
+/// ```
+pub fn rsplit_on_delimiters<'a, P: Pattern>(input_string: &'a str, pattern: P) -> Vec<&'a str> {
+    let mut output = split_on_delimiters(input_string, pattern);
+    output.reverse();
+    output
+}
+
+/// Split on multiple delimiters, returning the segments from the end of the string toward the
+/// start. This method returns a Vec of owned Strings rather than string slices; see
+/// [`rsplit_on_delimiters`] for semantics.
+///
+/// # Parameters:
+/// * `input_string`: A string slice to be split.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns:
+/// * A `Vec<String>` containing substrings of the input string `s`, rightmost segment first.
+///
+/// # Examples:
+/// ```
+/// use rust_strings::splits::rsplit_on_delimiters_returns_owned;
+/// let input = "a::b::c";
+/// let expected_output: Vec<String> = ["c".to_string(), "b".to_string(), "a".to_string()].to_vec();
+/// assert_eq!(expected_output, rsplit_on_delimiters_returns_owned(input, "::"));
+/// ```
+pub fn rsplit_on_delimiters_returns_owned<P: Pattern>(s: &str, pattern: P) -> Vec<String> {
+    rsplit_on_delimiters(s, pattern)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Splits a string into substrings while keeping the delimiter at the end of each substring,
+/// returning them from the end of the string toward the start.
+///
+/// This produces the same segments as [`split_keeping_delimiter`], but in reverse order
+/// (rightmost segment first); they are found by a left-to-right scan whose result is reversed.
+///
+/// # Parameters
+/// * `s`: A string slice to be split.
+/// * `pattern`: anything implementing [`Pattern`] used as the delimiter to split the string.
+///
+/// # Returns
+/// * A `Vec<&str>` containing substrings of the input string `s`, rightmost segment first. Each
+/// substring includes the delimiter at the end, and the substring after the last delimiter is
+/// not included (mirroring [`split_keeping_delimiter`]'s exclusion of the trailing undelimited
+/// substring).
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::rsplit_keeping_delimiter;
+/// let s = "hello,world,here";
+/// let result = rsplit_keeping_delimiter(s, ',');
+/// assert_eq!(result, vec!["world,", "hello,"]);
+/// ```
+pub fn rsplit_keeping_delimiter<'a, P: Pattern>(s: &'a str, pattern: P) -> Vec<&'a str> {
+    let mut output = split_keeping_delimiter(s, pattern);
+    output.reverse();
+    output
+}
+
+/// Splits a given input string by the specified delimiter while retaining the delimiter at the
+/// end of each split substring, returning them from the end of the string toward the start.
+/// Returns the results as a vector of owned strings; see [`rsplit_keeping_delimiter`] for
+/// semantics.
+///
+/// # Arguments
+/// * `input_string` - A reference to the string that will be split.
+/// * `pattern` - anything implementing [`Pattern`] used as the delimiter to split the string.
+///
+/// # Returns
+/// * `Vec<String>` - A vector of owned strings resulting from the split, rightmost segment
+///   first, each containing the delimiter at the end.
+///
+/// # Example
+/// ```
+/// use rust_strings::splits::rsplit_keeping_delimiter_returns_owned;
+/// let input = "hello,world,here";
+/// let result = rsplit_keeping_delimiter_returns_owned(input, ',');
+/// assert_eq!(result, vec!["world,", "hello,"]);
+/// ```
+pub fn rsplit_keeping_delimiter_returns_owned<P: Pattern>(
+    input_string: &str,
+    pattern: P,
+) -> Vec<String> {
+    rsplit_keeping_delimiter(input_string, pattern)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Splits a string slice into at most `n` parts, scanning from the end of the string toward the
+/// start. The first `n - 1` items returned are the rightmost segments; the final item is
+/// whatever prefix of `input_string` is left over, unscanned.
+///
+/// # Arguments:
+/// * `input_string`: a string slice to be split into parts.
+/// * `pattern`: anything implementing [`Pattern`] used to determine how/where to split the input_string
+/// * `n`: usize - the maximum number of items yielded.
+///
+/// # Returns
+/// * `Vec<&str>` - at most `n` substrings, rightmost segment first, with the leftover prefix as
+///   the final item.
+///
+/// # Examples:
+/// ```
+/// use rust_strings::splits::rsplitn_into_parts;
+/// let input_string = "This is a string.";
+/// let expected_outcome = vec!["string.", "a", "This is"];
+/// assert_eq!(expected_outcome, rsplitn_into_parts(input_string, ' ', 3));
+/// ```
+pub fn rsplitn_into_parts<'a, P: Pattern>(
+    input_string: &'a str,
+    pattern: P,
+    n: usize,
+) -> Vec<&'a str> {
+    let mut output: Vec<&str> = Vec::new();
+    if n == 0 {
+        return output;
+    }
+    let mut remainder = input_string;
+    while output.len() + 1 < n {
+        match rfind_match(remainder, &pattern) {
+            Some((idx, len)) => {
+                output.push(&remainder[idx + len..]);
+                remainder = &remainder[..idx];
+            }
+            None => break,
         }
-    } else {
-        output = input_string
-            .splitn(n, delimiter)
-            .map(String::from)
-            .collect();
+    }
+    output.push(remainder);
+    output
+}
+
+/// Splits a string slice into at most `n` parts, scanning from the end of the string toward the
+/// start. This fn returns owned strings in the vector; see [`rsplitn_into_parts`] for semantics.
+///
+/// # Arguments:
+/// * `input_string`: a string slice to be split into parts.
+/// * `pattern`: anything implementing [`Pattern`] used to determine how/where to split the input_string
+/// * `n`: usize - the maximum number of items yielded.
+///
+/// # Returns
+/// * `Vec<String>` - at most `n` substrings, rightmost segment first, with the leftover prefix as
+///   the final item.
+///
+/// # Examples:
+/// ```
+/// use rust_strings::splits::rsplitn_into_parts_returns_owned;
+/// let input_string = "This is a string.";
+/// let expected_outcome = vec!["string.".to_string(), "a".to_string(), "This is".to_string()];
+/// assert_eq!(expected_outcome, rsplitn_into_parts_returns_owned(input_string, ' ', 3));
+/// ```
+pub fn rsplitn_into_parts_returns_owned<P: Pattern>(
+    input_string: &str,
+    pattern: P,
+    n: usize,
+) -> Vec<String> {
+    rsplitn_into_parts(input_string, pattern, n)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Finds the byte offset of the first match of `pattern` in `s`, scanning left to right.
+///
+/// # Arguments
+/// * `s`: A string slice to search.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns
+/// * `Option<usize>` - the byte offset of the first match, or `None` if `pattern` never matches.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::find;
+/// assert_eq!(find("abcXdefXghi", 'X'), Some(3));
+/// assert_eq!(find("abcdefghi", 'X'), None);
+/// ```
+pub fn find<P: Pattern>(s: &str, pattern: P) -> Option<usize> {
+    let mut idx = 0;
+    while idx < s.len() {
+        if pattern.matches_at(s, idx).is_some() {
+            return Some(idx);
+        }
+        idx += char_len_at(s, idx);
+    }
+    None
+}
+
+/// Finds the byte offset of the last match of `pattern` in `s`, scanning right to left.
+///
+/// # Arguments
+/// * `s`: A string slice to search.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns
+/// * `Option<usize>` - the byte offset of the last match, or `None` if `pattern` never matches.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::rfind;
+/// assert_eq!(rfind("abcXdefXghi", 'X'), Some(7));
+/// assert_eq!(rfind("abcdefghi", 'X'), None);
+/// ```
+pub fn rfind<P: Pattern>(s: &str, pattern: P) -> Option<usize> {
+    rfind_match(s, &pattern).map(|(idx, _)| idx)
+}
+
+/// Finds every non-overlapping match of `pattern` in `s`, scanning left to right.
+///
+/// After a match of length `k` at byte offset `i`, scanning resumes at `i + k`; a zero-length
+/// match is not recorded and scanning advances by one `char` instead, so this never loops
+/// forever.
+///
+/// # Arguments
+/// * `s`: A string slice to search.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns
+/// * `Vec<(usize, &str)>` - the byte offset and matched slice of every non-overlapping,
+///   non-empty match.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::match_indices;
+/// let expected = vec![(3, "X"), (7, "X")];
+/// assert_eq!(expected, match_indices("abcXdefXghi", 'X'));
+/// ```
+pub fn match_indices<'a, P: Pattern>(s: &'a str, pattern: P) -> Vec<(usize, &'a str)> {
+    let mut output = Vec::new();
+    let mut idx = 0;
+    while idx < s.len() {
+        match pattern.matches_at(s, idx) {
+            Some(len) if len > 0 => {
+                output.push((idx, &s[idx..idx + len]));
+                idx += len;
+            }
+            _ => idx += char_len_at(s, idx),
+        }
+    }
+    output
+}
+
+/// Splits `s` on `pattern`, treating the pattern as a terminator rather than a separator.
+///
+/// This preserves every interior empty segment (unlike [`split_on_delimiters`], which strips
+/// all empty segments), but if `s` ends exactly at a match of `pattern`, the one trailing empty
+/// segment that produces is dropped. Use this for field-preserving data like CSV rows, where
+/// `"a,,b"` should split to `["a", "", "b"]` and a trailing terminator shouldn't add a spurious
+/// empty field.
+///
+/// # Arguments
+/// * `s`: A string slice to be split.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns
+/// * `Vec<&str>` containing every segment of `s`, interior empty segments included.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::split_terminator;
+/// assert_eq!(split_terminator("a,,b", ','), vec!["a", "", "b"]);
+/// assert_eq!(split_terminator("a,,b,", ','), vec!["a", "", "b"]);
+/// assert_eq!(split_terminator("a,b", ','), vec!["a", "b"]);
+/// ```
+pub fn split_terminator<'a, P: Pattern>(s: &'a str, pattern: P) -> Vec<&'a str> {
+    let mut output: Vec<&str> = split_iter(s, pattern).collect();
+    if !s.is_empty() && output.last().is_some_and(|last| last.is_empty()) {
+        output.pop();
     }
     output
 }
 
-// Remove common prefixes/suffixes
-//pub fn trim_common_prefix(strings: &[&str]) -> Vec<&str>
+/// Splits `s` on `pattern`, treating the pattern as a terminator rather than a separator.
+/// This fn returns owned strings in the vector; see [`split_terminator`] for semantics.
+///
+/// # Arguments
+/// * `s`: A string slice to be split.
+/// * `pattern`: anything implementing [`Pattern`] - a `char`, a `&str`, a `&[char]`, a
+///   `[char; N]`, or an `Fn(char) -> bool` predicate.
+///
+/// # Returns
+/// * `Vec<String>` containing every segment of `s`, interior empty segments included.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::split_terminator_returns_owned;
+/// let expected: Vec<String> = vec!["a".to_string(), "".to_string(), "b".to_string()];
+/// assert_eq!(expected, split_terminator_returns_owned("a,,b,", ','));
+/// ```
+pub fn split_terminator_returns_owned<P: Pattern>(s: &str, pattern: P) -> Vec<String> {
+    split_terminator(s, pattern)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Computes the byte length of the longest prefix shared by every string in `strings`.
+///
+/// Returns `0` if `strings` has fewer than two elements, since a single string (or no strings
+/// at all) has no other string to share a prefix with. The returned length always lands on a
+/// `char` boundary of `strings[0]`.
+fn common_prefix_len(strings: &[&str]) -> usize {
+    if strings.len() < 2 {
+        return 0;
+    }
+    let shortest = strings.iter().map(|s| s.len()).min().unwrap_or(0);
+    let first = strings[0].as_bytes();
+    let mut prefix_len = 0;
+    while prefix_len < shortest && strings[1..].iter().all(|s| s.as_bytes()[prefix_len] == first[prefix_len]) {
+        prefix_len += 1;
+    }
+    while prefix_len > 0 && !strings[0].is_char_boundary(prefix_len) {
+        prefix_len -= 1;
+    }
+    prefix_len
+}
+
+/// Computes the byte length of the longest suffix shared by every string in `strings`.
+///
+/// Returns `0` if `strings` has fewer than two elements. The returned length always lands on a
+/// `char` boundary of `strings[0]`.
+fn common_suffix_len(strings: &[&str]) -> usize {
+    if strings.len() < 2 {
+        return 0;
+    }
+    let shortest = strings.iter().map(|s| s.len()).min().unwrap_or(0);
+    let first = strings[0].as_bytes();
+    let first_len = first.len();
+    let mut suffix_len = 0;
+    while suffix_len < shortest
+        && strings[1..].iter().all(|s| {
+            let bytes = s.as_bytes();
+            bytes[bytes.len() - 1 - suffix_len] == first[first_len - 1 - suffix_len]
+        })
+    {
+        suffix_len += 1;
+    }
+    while suffix_len > 0 && !strings[0].is_char_boundary(strings[0].len() - suffix_len) {
+        suffix_len -= 1;
+    }
+    suffix_len
+}
+
+/// Trims the longest common leading substring shared by every string in `strings`, returning
+/// each input with that prefix removed.
+///
+/// # Arguments
+/// * `strings`: a slice of string slices to trim.
+///
+/// # Returns
+/// * `Vec<&str>` - each input string with the common prefix removed. An empty `strings` slice
+///   returns an empty vec; a single-element slice is returned unchanged (there being no other
+///   string to share a prefix with).
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::trim_common_prefix;
+/// let strings = ["prefix_one", "prefix_two", "prefix_three"];
+/// let expected: Vec<&str> = vec!["one", "two", "three"];
+/// assert_eq!(expected, trim_common_prefix(&strings));
+/// ```
+///
+/// ```
+/// use rust_strings::splits::trim_common_prefix;
+/// let strings = ["only_one"];
+/// assert_eq!(vec!["only_one"], trim_common_prefix(&strings));
+/// ```
+pub fn trim_common_prefix<'a>(strings: &[&'a str]) -> Vec<&'a str> {
+    let prefix_len = common_prefix_len(strings);
+    strings.iter().map(|s| &s[prefix_len..]).collect()
+}
+
+/// Trims the longest common leading substring shared by every string in `strings`, returning
+/// each input with that prefix removed, as owned `String`s. See [`trim_common_prefix`] for
+/// semantics.
+///
+/// # Arguments
+/// * `strings`: a slice of string slices to trim.
+///
+/// # Returns
+/// * `Vec<String>` - each input string with the common prefix removed.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::trim_common_prefix_returns_owned;
+/// let strings = ["prefix_one", "prefix_two", "prefix_three"];
+/// let expected = vec!["one".to_string(), "two".to_string(), "three".to_string()];
+/// assert_eq!(expected, trim_common_prefix_returns_owned(&strings));
+/// ```
+pub fn trim_common_prefix_returns_owned(strings: &[&str]) -> Vec<String> {
+    trim_common_prefix(strings)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Trims the longest common trailing substring shared by every string in `strings`, returning
+/// each input with that suffix removed.
+///
+/// # Arguments
+/// * `strings`: a slice of string slices to trim.
+///
+/// # Returns
+/// * `Vec<&str>` - each input string with the common suffix removed. An empty `strings` slice
+///   returns an empty vec; a single-element slice is returned unchanged (there being no other
+///   string to share a suffix with).
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::trim_common_suffix;
+/// let strings = ["one_suffix", "two_suffix", "three_suffix"];
+/// let expected: Vec<&str> = vec!["one", "two", "three"];
+/// assert_eq!(expected, trim_common_suffix(&strings));
+/// ```
+///
+/// ```
+/// use rust_strings::splits::trim_common_suffix;
+/// let strings = ["only_one"];
+/// assert_eq!(vec!["only_one"], trim_common_suffix(&strings));
+/// ```
+pub fn trim_common_suffix<'a>(strings: &[&'a str]) -> Vec<&'a str> {
+    let suffix_len = common_suffix_len(strings);
+    strings.iter().map(|s| &s[..s.len() - suffix_len]).collect()
+}
+
+/// Trims the longest common trailing substring shared by every string in `strings`, returning
+/// each input with that suffix removed, as owned `String`s. See [`trim_common_suffix`] for
+/// semantics.
+///
+/// # Arguments
+/// * `strings`: a slice of string slices to trim.
+///
+/// # Returns
+/// * `Vec<String>` - each input string with the common suffix removed.
+///
+/// # Examples
+/// ```
+/// use rust_strings::splits::trim_common_suffix_returns_owned;
+/// let strings = ["one_suffix", "two_suffix", "three_suffix"];
+/// let expected = vec!["one".to_string(), "two".to_string(), "three".to_string()];
+/// assert_eq!(expected, trim_common_suffix_returns_owned(&strings));
+/// ```
+pub fn trim_common_suffix_returns_owned(strings: &[&str]) -> Vec<String> {
+    trim_common_suffix(strings)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}